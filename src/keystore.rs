@@ -0,0 +1,129 @@
+//! Web3 Secret Storage (keystore v3) output, compatible with geth/ethstore.
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use ctr::Ctr128BE;
+use k256::SecretKey;
+use rand::{rngs::OsRng, RngCore};
+use scrypt::{scrypt, Params};
+use serde::Serialize;
+use sha3::{Digest, Keccak256};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+use zeroize::Zeroize;
+
+type Aes128Ctr = Ctr128BE<aes::Aes128>;
+
+const SCRYPT_LOG_N: u8 = 18; // n = 262144
+const SCRYPT_N: u32 = 1 << SCRYPT_LOG_N;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const DKLEN: usize = 32;
+
+#[derive(Serialize)]
+struct KeystoreV3 {
+    version: u8,
+    id: String,
+    address: String,
+    crypto: CryptoParams,
+}
+
+#[derive(Serialize)]
+struct CryptoParams {
+    cipher: &'static str,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: &'static str,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+#[derive(Serialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Serialize)]
+struct KdfParams {
+    n: u32,
+    r: u32,
+    p: u32,
+    dklen: usize,
+    salt: String,
+}
+
+/// Prompt for a passphrase and write `sk` as an encrypted keystore-v3 JSON file under `dir`.
+/// Returns the path of the written file.
+pub fn write_keystore(dir: &str, address: &[u8; 20], sk: &SecretKey) -> std::io::Result<PathBuf> {
+    let passphrase = rpassword::prompt_password("Keystore passphrase: ")
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    let confirm = rpassword::prompt_password("Confirm passphrase: ")
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    if passphrase != confirm {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "passphrases did not match"));
+    }
+
+    let mut salt = [0u8; 32];
+    OsRng.fill_bytes(&mut salt);
+    let mut iv = [0u8; 16];
+    OsRng.fill_bytes(&mut iv);
+
+    let params = Params::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P, DKLEN).expect("valid scrypt params");
+    let mut dk = [0u8; DKLEN];
+    scrypt(passphrase.as_bytes(), &salt, &params, &mut dk).expect("scrypt derivation failed");
+
+    let mut ciphertext = sk.to_bytes().to_vec();
+    let mut cipher = Aes128Ctr::new((&dk[0..16]).into(), (&iv).into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let mut mac_input = Vec::with_capacity(16 + ciphertext.len());
+    mac_input.extend_from_slice(&dk[16..32]);
+    mac_input.extend_from_slice(&ciphertext);
+    let mac = Keccak256::digest(&mac_input);
+    dk.zeroize();
+
+    let keystore = KeystoreV3 {
+        version: 3,
+        id: Uuid::new_v4().to_string(),
+        address: hex::encode(address),
+        crypto: CryptoParams {
+            cipher: "aes-128-ctr",
+            cipherparams: CipherParams { iv: hex::encode(iv) },
+            ciphertext: hex::encode(&ciphertext),
+            kdf: "scrypt",
+            kdfparams: KdfParams {
+                n: SCRYPT_N,
+                r: SCRYPT_R,
+                p: SCRYPT_P,
+                dklen: DKLEN,
+                salt: hex::encode(salt),
+            },
+            mac: hex::encode(mac),
+        },
+    };
+
+    std::fs::create_dir_all(dir)?;
+    let path = Path::new(dir).join(format!("UTC--keystore-{}.json", keystore.id));
+    let json = serde_json::to_string_pretty(&keystore).expect("serialize keystore");
+    let mut file = open_keystore_file(&path)?;
+    file.write_all(json.as_bytes())?;
+
+    Ok(path)
+}
+
+/// Create the keystore file restricted to owner read/write (0600), matching geth/ethstore, so the
+/// encrypted key is never left group/world-readable by the process's default umask.
+#[cfg(unix)]
+fn open_keystore_file(path: &Path) -> std::io::Result<std::fs::File> {
+    use std::os::unix::fs::OpenOptionsExt;
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+}
+
+#[cfg(not(unix))]
+fn open_keystore_file(path: &Path) -> std::io::Result<std::fs::File> {
+    std::fs::File::create(path)
+}
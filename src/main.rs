@@ -1,10 +1,14 @@
 use rand::RngCore;
 use bip39::{Language, Mnemonic};
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use coins_bip32::{path::DerivationPath, prelude::*, xkeys::Parent};
 use hex::ToHex;
-use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey, SecretKey};
-use rand::rngs::OsRng;
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, Field},
+    FieldBytes, PublicKey, ProjectivePoint, Scalar, SecretKey,
+};
+use rand::{rngs::OsRng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use rayon::prelude::*;
 use sha3::{Digest, Keccak256};
 use std::{
@@ -17,6 +21,8 @@ use std::{
 };
 use zeroize::Zeroize;
 
+mod keystore;
+
 /// How to compare the vanity pattern.
 #[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
 enum Mode {
@@ -26,16 +32,79 @@ enum Mode {
     Checksum,
 }
 
+/// A single vanity target to search for, alongside every other configured target. All conditions
+/// set on one `Target` must match together (AND); a candidate address is reported as a hit the
+/// moment it satisfies any one `Target` in the full list (OR across targets). The positional
+/// `PATTERN` and its paired `--suffix` combine into one compound AND target, preserving the
+/// original "prefix and suffix together" search; every additional `--pattern`/`--suffix`/
+/// `--contains`/`--patterns-file` entry is folded in as its own single-condition OR target.
+#[derive(Clone, Debug, Default)]
+struct Target {
+    prefix: Option<String>,
+    suffix: Option<String>,
+    contains: Option<String>,
+}
+
+impl Target {
+    fn prefix(pattern: String) -> Self {
+        Target { prefix: Some(pattern), ..Default::default() }
+    }
+
+    fn suffix(pattern: String) -> Self {
+        Target { suffix: Some(pattern), ..Default::default() }
+    }
+
+    fn contains(pattern: String) -> Self {
+        Target { contains: Some(pattern), ..Default::default() }
+    }
+
+    /// Every pattern string configured on this target, for validation.
+    fn patterns(&self) -> impl Iterator<Item = &str> {
+        [self.prefix.as_deref(), self.suffix.as_deref(), self.contains.as_deref()]
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// Which key-generation engine to use for EOA vanity search.
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug, Default)]
+enum Engine {
+    /// BIP-39 mnemonic + BIP-32 HD derivation (slower, produces a recoverable seed phrase).
+    #[default]
+    Mnemonic,
+    /// Incremental EC point-addition walk (much faster, produces a raw private key only).
+    Fast,
+    /// Deterministic passphrase-seeded ("brain wallet") search (--brain). Weak phrases are stealable.
+    Brain,
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about = "Ethereum vanity address generator in Rust")]
 struct Args {
-    /// Prefix to match (without 0x). Examples: "dead", "c0ffee". Optional if suffix is provided.
-    #[arg(required_unless_present = "suffix")]
+    /// Prefix to match (without 0x). Examples: "dead", "c0ffee". Paired with the first `--suffix`
+    /// (if any) as one compound AND target — give both to require the address start with this
+    /// prefix AND end with that suffix. Optional if another pattern source is given.
+    #[arg(required_unless_present_any = ["suffix", "patterns", "contains", "patterns_file"])]
     pattern: Option<String>,
 
-    /// Suffix to match (without 0x). Examples: "beef", "1337".
-    #[arg(long, required_unless_present = "pattern")]
-    suffix: Option<String>,
+    /// Suffix to match (without 0x); repeatable. Examples: "beef", "1337". The first value is
+    /// ANDed with PATTERN into one compound target; any further values are separate OR targets.
+    #[arg(long, required_unless_present_any = ["pattern", "patterns", "contains", "patterns_file"])]
+    suffix: Vec<String>,
+
+    /// Additional prefix pattern(s) to match; repeatable. Each is its own OR target — a hit on ANY
+    /// configured target (the PATTERN+suffix pair, these, --contains, or --patterns-file) is reported.
+    #[arg(long = "pattern", value_name = "HEX")]
+    patterns: Vec<String>,
+
+    /// Pattern(s) to match anywhere in the address, not just at the start/end; repeatable. Each is
+    /// its own OR target, widening the hit rate for casual searches.
+    #[arg(long, value_name = "HEX")]
+    contains: Vec<String>,
+
+    /// Load additional OR targets from a file, one per line formatted "prefix:VALUE", "suffix:VALUE", or "contains:VALUE"
+    #[arg(long, value_name = "FILE")]
+    patterns_file: Option<String>,
 
     /// Matching mode: lowercase or checksum (EIP-55 case-sensitive)
     #[arg(long, value_enum, default_value_t = Mode::Lower)]
@@ -60,36 +129,362 @@ struct Args {
     /// Progress update interval in seconds
     #[arg(long, default_value_t = 5)]
     progress_interval: u64,
+
+    /// EOA key-generation engine: HD mnemonic derivation, the fast incremental point-addition walk, or a passphrase-seeded brain wallet (--brain)
+    #[arg(long, value_enum, default_value_t = Engine::Mnemonic)]
+    engine: Engine,
+
+    /// Number of points to walk per random base scalar before picking a new one (--engine fast only)
+    #[arg(long, default_value_t = 1_000_000)]
+    points_per_base: u64,
+
+    /// Print only the raw private key instead of a mnemonic/derivation path (always on for --engine fast)
+    #[arg(long)]
+    raw_key: bool,
+
+    /// Passphrase to stretch into a deterministic key (required for --engine brain)
+    #[arg(long, value_name = "PHRASE")]
+    brain: Option<String>,
+
+    /// Iterated keccak256 rounds used to stretch the brain-wallet passphrase into a scalar
+    #[arg(long, default_value_t = 8192)]
+    brain_rounds: u32,
+
+    /// Write the found private key as an encrypted Web3 Secret Storage (keystore-v3) JSON file in this directory, instead of printing it raw
+    #[arg(long, value_name = "DIR")]
+    keystore: Option<String>,
+
+    /// Seed a deterministic ChaCha20 RNG instead of OsRng (32 bytes hex), so a search can be exactly reproduced or split across machines with --shard
+    #[arg(long, value_name = "HEX")]
+    seed: Option<String>,
+
+    /// Cover only one disjoint slice of the keyspace, formatted "i/N" (0-indexed). Requires --seed so cooperating machines derive non-overlapping work from the same seed.
+    #[arg(long, value_name = "I/N")]
+    shard: Option<String>,
+
+    /// Search for a vanity contract address instead of an EOA (CREATE / CREATE2)
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// CREATE: search deployer nonces for a vanity `keccak256(rlp([deployer, nonce]))[12..]` address
+    Create {
+        /// Deployer address (with or without 0x prefix)
+        #[arg(long)]
+        deployer: String,
+    },
+
+    /// CREATE2: search salts for a vanity `keccak256(0xff ++ deployer ++ salt ++ init_code_hash)[12..]` address
+    Create2 {
+        /// Deployer address (with or without 0x prefix)
+        #[arg(long)]
+        deployer: String,
+
+        /// keccak256 hash of the contract init code (with or without 0x prefix)
+        #[arg(long)]
+        init_code_hash: String,
+    },
 }
 
 fn main() {
     let args = Args::parse();
 
-    // Normalize the target patterns according to selected mode
-    let want_prefix = args.pattern.as_ref().map(|p| match args.mode {
-        Mode::Lower => p.to_ascii_lowercase(),
-        Mode::Checksum => p.clone(),
-    });
+    let targets = build_targets(&args);
 
-    let want_suffix = args.suffix.as_ref().map(|s| match args.mode {
-        Mode::Lower => s.to_ascii_lowercase(),
-        Mode::Checksum => s.clone(),
+    if let Some(t) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(t)
+            .build_global()
+            .ok();
+    }
+
+    let seed = args.seed.as_deref().map(|s| {
+        parse_hash32(s).unwrap_or_else(|e| {
+            eprintln!("Invalid seed: {}", e);
+            std::process::exit(1);
+        })
     });
 
-    if let Some(ref prefix) = want_prefix {
-        if !is_valid_hex_prefix(prefix) {
-            eprintln!("prefix pattern must be a valid hex string (0-9a-fA-F), no '0x'");
+    let shard = args.shard.as_deref().map(|s| {
+        parse_shard(s).unwrap_or_else(|e| {
+            eprintln!("Invalid shard: {}", e);
             std::process::exit(1);
+        })
+    });
+    if shard.is_some() && seed.is_none() {
+        eprintln!("--shard requires --seed, so cooperating machines derive non-overlapping work from the same seed");
+        std::process::exit(1);
+    }
+    let shard = shard.unwrap_or((0, 1));
+
+    match &args.command {
+        None => match args.engine {
+            Engine::Mnemonic => run_mnemonic_search(&args, seed, shard, &targets),
+            Engine::Fast => run_fast_search(&args, seed, shard, &targets),
+            Engine::Brain => {
+                let phrase = args.brain.clone().unwrap_or_else(|| {
+                    eprintln!("--engine brain requires --brain <phrase>");
+                    std::process::exit(1);
+                });
+                run_brain_search(&args, &phrase, shard, &targets);
+            }
+        },
+        Some(Command::Create { deployer }) => {
+            let deployer = parse_address(deployer).unwrap_or_else(|e| {
+                eprintln!("Invalid deployer address: {}", e);
+                std::process::exit(1);
+            });
+            run_create_search(&args, deployer, shard, &targets);
+        }
+        Some(Command::Create2 { deployer, init_code_hash }) => {
+            let deployer = parse_address(deployer).unwrap_or_else(|e| {
+                eprintln!("Invalid deployer address: {}", e);
+                std::process::exit(1);
+            });
+            let init_code_hash = parse_hash32(init_code_hash).unwrap_or_else(|e| {
+                eprintln!("Invalid init code hash: {}", e);
+                std::process::exit(1);
+            });
+            run_create2_search(&args, deployer, init_code_hash, seed, shard, &targets);
         }
     }
+}
 
-    if let Some(ref suffix) = want_suffix {
-        if !is_valid_hex_prefix(suffix) {
-            eprintln!("suffix pattern must be a valid hex string (0-9a-fA-F), no '0x'");
-            std::process::exit(1);
+/// Check a candidate address against a single target under the selected `Mode`. Every condition
+/// configured on the target (prefix/suffix/contains) must match — a condition left unset (`None`)
+/// is vacuously satisfied.
+fn target_matches(addr_lower: &str, checksummed: &str, mode: Mode, target: &Target) -> bool {
+    let haystack = match mode {
+        Mode::Lower => addr_lower,
+        Mode::Checksum => checksummed.strip_prefix("0x").unwrap(),
+    };
+    target.prefix.as_deref().map_or(true, |p| haystack.starts_with(p))
+        && target.suffix.as_deref().map_or(true, |s| haystack.ends_with(s))
+        && target.contains.as_deref().map_or(true, |c| haystack.contains(c))
+}
+
+/// Return the first configured target that matches this candidate address, if any.
+fn first_match<'a>(addr_lower: &str, checksummed: &str, mode: Mode, targets: &'a [Target]) -> Option<&'a Target> {
+    targets.iter().find(|t| target_matches(addr_lower, checksummed, mode, t))
+}
+
+/// Print which part(s) of the address matched the target's configured condition(s).
+fn print_matched_target(addr_lower: &str, checksummed: &str, mode: Mode, target: &Target) {
+    let haystack = match mode {
+        Mode::Lower => addr_lower,
+        Mode::Checksum => checksummed.strip_prefix("0x").unwrap(),
+    };
+    let case_label = match mode {
+        Mode::Lower => "lowercase",
+        Mode::Checksum => "checksum",
+    };
+    if let Some(prefix) = &target.prefix {
+        println!("  Matched {} prefix \"{}\": {}", case_label, prefix, &haystack[..prefix.len().min(haystack.len())]);
+    }
+    if let Some(suffix) = &target.suffix {
+        let start = haystack.len().saturating_sub(suffix.len());
+        println!("  Matched {} suffix \"{}\": {}", case_label, suffix, &haystack[start..]);
+    }
+    if let Some(contains) = &target.contains {
+        println!("  Matched {} contains \"{}\"", case_label, contains);
+    }
+}
+
+/// Number of hex characters in an address (20 bytes).
+const ADDRESS_HEX_LEN: usize = 40;
+
+/// Probability that a single candidate address matches a given pattern at one fixed position.
+/// Case doesn't matter in `Mode::Lower`; in `Mode::Checksum` every hex letter (a-f) roughly halves
+/// the per-position probability because its case must also match, on top of the usual 1-in-16 per
+/// character (digits 0-9 carry no case under EIP-55, so they don't get this extra factor).
+fn single_position_probability(mode: Mode, pattern: &str) -> f64 {
+    let mut p = 1f64;
+    for c in pattern.chars() {
+        p /= 16.0;
+        if mode == Mode::Checksum && c.is_ascii_alphabetic() {
+            p /= 2.0;
+        }
+    }
+    p
+}
+
+/// Expected-attempts estimate for one target, used for the upfront difficulty table and the live
+/// progress-thread ETA. The prefix/suffix conditions only ever get one shot per candidate address,
+/// so each contributes its `single_position_probability` directly; `contains` gets one shot per
+/// valid start position (`ADDRESS_HEX_LEN - len + 1` of them), so its contribution is scaled up by
+/// that position count (accurate for the short patterns these searches are feasible for, where
+/// collisions across positions are vanishingly unlikely). A target's overall hit probability is the
+/// product of whichever conditions it has configured (treated as independent), and its expected
+/// attempts is the reciprocal of that.
+fn expected_attempts(mode: Mode, target: &Target) -> f64 {
+    let mut p = 1f64;
+    if let Some(prefix) = &target.prefix {
+        p *= single_position_probability(mode, prefix);
+    }
+    if let Some(suffix) = &target.suffix {
+        p *= single_position_probability(mode, suffix);
+    }
+    if let Some(contains) = &target.contains {
+        let positions = (ADDRESS_HEX_LEN.saturating_sub(contains.len()) + 1) as f64;
+        p *= single_position_probability(mode, contains) * positions;
+    }
+    1.0 / p
+}
+
+/// The easiest (lowest expected-attempts) configured target, used to estimate ETA in the live
+/// progress thread.
+fn min_expected_attempts(mode: Mode, targets: &[Target]) -> f64 {
+    targets
+        .iter()
+        .map(|t| expected_attempts(mode, t))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Roughly measure this process's achievable addresses/sec by running `attempt` (the engine's
+/// real per-candidate codepath) on one thread for a short fixed window, then scaling by the
+/// worker thread count. Only used for the upfront difficulty/ETA table below — the live progress
+/// thread reports the true measured rate once the search is actually running.
+fn calibrate_rate(mut attempt: impl FnMut()) -> f64 {
+    let window = Duration::from_millis(200);
+    let start = Instant::now();
+    let mut n = 0u64;
+    while start.elapsed() < window {
+        attempt();
+        n += 1;
+    }
+    (n as f64 / window.as_secs_f64()) * rayon::current_num_threads() as f64
+}
+
+/// Describe a target's configured condition(s) for the difficulty table, e.g. `prefix "dead"` or
+/// `prefix "dead" + suffix "beef"` for the compound AND target.
+fn describe_target(target: &Target) -> String {
+    let mut parts = Vec::new();
+    if let Some(prefix) = &target.prefix {
+        parts.push(format!("prefix \"{}\"", prefix));
+    }
+    if let Some(suffix) = &target.suffix {
+        parts.push(format!("suffix \"{}\"", suffix));
+    }
+    if let Some(contains) = &target.contains {
+        parts.push(format!("contains \"{}\"", contains));
+    }
+    parts.join(" + ")
+}
+
+/// Print the configured targets and an upfront difficulty/ETA table using a brief measured sample
+/// of this run's actual per-address rate.
+fn print_difficulty_table(mode: Mode, targets: &[Target], rate: f64) {
+    println!("Targets (hit on ANY target; a target's own prefix/suffix/contains conditions are ANDed together):");
+    for t in targets {
+        let expected = expected_attempts(mode, t);
+        let eta = if rate > 0.0 { expected / rate } else { f64::INFINITY };
+        println!(
+            "  {} — ~{} expected attempts, ETA {}",
+            describe_target(t),
+            format_number(expected as u64),
+            format_duration(eta)
+        );
+    }
+    println!("Measured rate: {} addr/sec", format_number(rate as u64));
+}
+
+/// Collect every target from the CLI into one normalized, hex-validated list. The positional
+/// `PATTERN` and its paired `--suffix` (the first one given) combine into a single compound AND
+/// target, matching the original "prefix and suffix together" search; any further `--suffix`
+/// values, every `--pattern`/`--contains`, and every `--patterns-file` entry are folded in as their
+/// own single-condition OR targets. A hit on any target in the returned list is reported as a match.
+fn build_targets(args: &Args) -> Vec<Target> {
+    let normalize = |s: &str| match args.mode {
+        Mode::Lower => s.to_ascii_lowercase(),
+        Mode::Checksum => s.to_string(),
+    };
+
+    let mut targets = Vec::new();
+
+    let mut suffixes = args.suffix.iter();
+    let paired_suffix = suffixes.next().map(|s| normalize(s));
+    if args.pattern.is_some() || paired_suffix.is_some() {
+        targets.push(Target {
+            prefix: args.pattern.as_deref().map(normalize),
+            suffix: paired_suffix,
+            contains: None,
+        });
+    }
+    for s in suffixes {
+        targets.push(Target::suffix(normalize(s)));
+    }
+    for p in &args.patterns {
+        targets.push(Target::prefix(normalize(p)));
+    }
+    for c in &args.contains {
+        targets.push(Target::contains(normalize(c)));
+    }
+    if let Some(path) = &args.patterns_file {
+        match load_patterns_file(path, &normalize) {
+            Ok(mut file_targets) => targets.append(&mut file_targets),
+            Err(e) => {
+                eprintln!("Failed to read --patterns-file {}: {}", path, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if targets.is_empty() {
+        eprintln!("at least one pattern is required: PATTERN, --pattern, --suffix, --contains, or --patterns-file");
+        std::process::exit(1);
+    }
+    for t in &targets {
+        for p in t.patterns() {
+            if !is_valid_hex_prefix(p) {
+                eprintln!("pattern \"{}\" must be a valid hex string (0-9a-fA-F), no '0x'", p);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    targets
+}
+
+/// Load additional single-condition OR targets from a file, one per line formatted
+/// "prefix:VALUE", "suffix:VALUE", or "contains:VALUE". Blank lines and lines starting with '#'
+/// are ignored.
+fn load_patterns_file(path: &str, normalize: &dyn Fn(&str) -> String) -> std::io::Result<Vec<Target>> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut targets = Vec::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
         }
+        let (kind_str, pattern) = line.split_once(':').ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("expected \"prefix:VALUE\", \"suffix:VALUE\", or \"contains:VALUE\", got \"{}\"", line),
+            )
+        })?;
+        let pattern = normalize(pattern);
+        let target = match kind_str {
+            "prefix" => Target::prefix(pattern),
+            "suffix" => Target::suffix(pattern),
+            "contains" => Target::contains(pattern),
+            other => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("unknown pattern kind \"{}\", expected prefix/suffix/contains", other),
+                ))
+            }
+        };
+        targets.push(target);
     }
 
+    Ok(targets)
+}
+
+/// Search for a vanity EOA address by generating random BIP-39 mnemonics and deriving addresses from them.
+fn run_mnemonic_search(args: &Args, run_seed: Option<[u8; 32]>, shard: (u64, u64), targets: &[Target]) {
     // Parse derivation path
     let base_path = match DerivationPath::from_str(&args.derivation_path) {
         Ok(p) => p,
@@ -100,21 +495,23 @@ fn main() {
     };
 
     println!("Searching for vanity address by generating random mnemonics...");
-    if let Some(ref prefix) = want_prefix {
-        println!("Prefix: {}", prefix);
-    }
-    if let Some(ref suffix) = want_suffix {
-        println!("Suffix: {}", suffix);
-    }
     println!("Checking first {} addresses per mnemonic", args.addresses_per_mnemonic);
     println!("Derivation path: {}", args.derivation_path);
 
-    if let Some(t) = args.threads {
-        rayon::ThreadPoolBuilder::new()
-            .num_threads(t)
-            .build_global()
-            .ok();
-    }
+    // Like the search loop below, stretch a mnemonic into a seed only once per
+    // `addresses_per_mnemonic` candidates and derive one address per candidate.
+    let per_mnemonic = args.addresses_per_mnemonic.max(1);
+    let mut calibration_seed = [0u8; 64];
+    let mut calibration_n = 0u32;
+    let rate = calibrate_rate(|| {
+        let index = calibration_n % per_mnemonic;
+        if index == 0 {
+            calibration_seed = Mnemonic::from_entropy(&[0u8; 16]).unwrap().to_seed("");
+        }
+        std::hint::black_box(gen_key_from_seed(&calibration_seed, &base_path, index));
+        calibration_n = calibration_n.wrapping_add(1);
+    });
+    print_difficulty_table(args.mode, targets, rate);
 
     let found = Arc::new(AtomicBool::new(false));
     let attempts = Arc::new(AtomicU64::new(0));
@@ -125,8 +522,9 @@ fn main() {
         let found_clone = Arc::clone(&found);
         let attempts_clone = Arc::clone(&attempts);
         let interval = Duration::from_secs(args.progress_interval);
-        let pattern_len = want_prefix.as_ref().map(|p| p.len()).unwrap_or(0) + want_suffix.as_ref().map(|s| s.len()).unwrap_or(0);
+        let min_expected = min_expected_attempts(args.mode, targets);
         let addresses_per_mnemonic = args.addresses_per_mnemonic;
+        let progress_interval = args.progress_interval;
 
         std::thread::spawn(move || {
             let mut last_attempts = 0u64;
@@ -135,17 +533,15 @@ fn main() {
                 let current_attempts = attempts_clone.load(Ordering::Relaxed);
                 let elapsed = start_time.elapsed().as_secs();
                 let rate = if elapsed > 0 {
-                    (current_attempts - last_attempts) / args.progress_interval
+                    (current_attempts - last_attempts) / progress_interval
                 } else {
                     0
                 };
 
                 let mnemonics_checked = current_attempts / addresses_per_mnemonic as u64;
 
-                // Estimate probability and time (adjusted for multiple addresses per mnemonic)
-                let probability = 16_f64.powi(pattern_len as i32);
                 let estimated_seconds = if rate > 0 {
-                    probability / rate as f64
+                    min_expected / rate as f64
                 } else {
                     f64::INFINITY
                 };
@@ -163,17 +559,19 @@ fn main() {
     }
 
     // Use an unbounded parallel iterator that keeps generating mnemonics until we find a match
-    (0u64..u64::MAX)
-        .into_par_iter()
-        .any(|_| {
+    search_indices(shard)
+        .any(|i| {
             if found.load(Ordering::Relaxed) {
                 return true;
             }
 
-            // Generate a random mnemonic
-            let mut rng = OsRng;
+            // Generate a mnemonic, from OsRng normally or from the deterministic per-attempt
+            // ChaCha stream when --seed was given
             let mut entropy = [0u8; 16]; // 12 words = 16 bytes of entropy
-            rng.fill_bytes(&mut entropy);
+            match run_seed {
+                Some(run_seed) => seeded_rng(&run_seed, shard, i).fill_bytes(&mut entropy),
+                None => OsRng.fill_bytes(&mut entropy),
+            }
             let mnemonic = Mnemonic::from_entropy(&entropy).expect("failed to generate mnemonic");
             let seed = mnemonic.to_seed("");
 
@@ -191,72 +589,36 @@ fn main() {
                 let addr_lower = hex::encode(addr_bytes);
                 let checksummed = to_eip55(&addr_lower);
 
-                let matches = match args.mode {
-                    Mode::Lower => {
-                        let prefix_match = want_prefix.as_ref().map_or(true, |p| addr_lower.starts_with(p));
-                        let suffix_match = want_suffix.as_ref().map_or(true, |s| addr_lower.ends_with(s));
-                        prefix_match && suffix_match
-                    }
-                    Mode::Checksum => {
-                        let addr_checksum = checksummed.strip_prefix("0x").unwrap();
-                        let prefix_match = want_prefix.as_ref().map_or(true, |p| addr_checksum.starts_with(p));
-                        let suffix_match = want_suffix.as_ref().map_or(true, |s| addr_checksum.ends_with(s));
-                        prefix_match && suffix_match
+                if let Some(matched) = first_match(&addr_lower, &checksummed, args.mode, targets) {
+                    // Claim the single winning slot before any printing or keystore I/O, so a
+                    // second thread that also matched this instant doesn't race it into
+                    // emit_private_key (which can prompt for a passphrase on stdin).
+                    if found.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+                        return true;
                     }
-                };
 
-                if matches {
                     let elapsed = start_time.elapsed();
                     let total_attempts = attempts.load(Ordering::Relaxed);
 
-                    // Print result and stop everyone else
-                    let sk_hex = sk.to_bytes().encode_hex::<String>();
-                    // Zeroize secret key bytes after we've copied the hex out
-                    let mut sk_bytes = sk.to_bytes();
-                    sk_bytes.zeroize();
+                    // Print result and stop everyone else. `sk` itself zeroizes on drop (k256's
+                    // SecretKey is zeroize-on-drop); the hex string we print from is the one copy
+                    // that outlives it, so scrub that explicitly once we're done with it below.
+                    let mut sk_hex = sk.to_bytes().encode_hex::<String>();
 
                     println!("\n🎉 Found matching address!");
                     println!("  Address (lower):   0x{}", addr_lower);
                     println!("  Address (EIP-55):  {}", checksummed);
-                    println!("  Mnemonic:          {}", mnemonic.to_string());
-                    println!("  Derivation index:  {}", derivation_index.unwrap());
-                    println!("  Full path:         {}/{}", args.derivation_path, derivation_index.unwrap());
-                    println!("  Private key (hex): 0x{}", sk_hex);
-
-                    // Optional: show the matched prefix/suffix to sanity-check
-                    match args.mode {
-                        Mode::Lower => {
-                            if let Some(ref prefix) = want_prefix {
-                                println!(
-                                    "  Matched lowercase prefix: {}",
-                                    &addr_lower[..prefix.len().min(addr_lower.len())]
-                                );
-                            }
-                            if let Some(ref suffix) = want_suffix {
-                                let start = addr_lower.len().saturating_sub(suffix.len());
-                                println!(
-                                    "  Matched lowercase suffix: {}",
-                                    &addr_lower[start..]
-                                );
-                            }
-                        }
-                        Mode::Checksum => {
-                            let chk = checksummed.strip_prefix("0x").unwrap();
-                            if let Some(ref prefix) = want_prefix {
-                                println!(
-                                    "  Matched checksum prefix: {}",
-                                    &chk[..prefix.len().min(chk.len())]
-                                );
-                            }
-                            if let Some(ref suffix) = want_suffix {
-                                let start = chk.len().saturating_sub(suffix.len());
-                                println!(
-                                    "  Matched checksum suffix: {}",
-                                    &chk[start..]
-                                );
-                            }
-                        }
+                    if args.raw_key {
+                        emit_private_key(args.keystore.as_deref(), &addr_bytes, &sk, &sk_hex);
+                    } else {
+                        println!("  Mnemonic:          {}", mnemonic.to_string());
+                        println!("  Derivation index:  {}", derivation_index.unwrap());
+                        println!("  Full path:         {}/{}", args.derivation_path, derivation_index.unwrap());
+                        emit_private_key(args.keystore.as_deref(), &addr_bytes, &sk, &sk_hex);
                     }
+                    sk_hex.zeroize();
+
+                    print_matched_target(&addr_lower, &checksummed, args.mode, matched);
 
                     println!("\n📊 Statistics:");
                     println!("  Mnemonics checked: {}", format_number(total_attempts / args.addresses_per_mnemonic as u64));
@@ -264,7 +626,6 @@ fn main() {
                     println!("  Time elapsed:      {:.2} seconds", elapsed.as_secs_f64());
                     println!("  Rate:              {} addr/sec", format_number((total_attempts as f64 / elapsed.as_secs_f64()) as u64));
 
-                    found.store(true, Ordering::SeqCst);
                     return true;
                 }
             }
@@ -273,6 +634,474 @@ fn main() {
         });
 }
 
+/// Search for a vanity EOA address using an incremental EC point-addition walk.
+///
+/// Each worker picks a random base scalar `k0`, computes `P0 = k0*G` once, then advances with a
+/// single point addition per step (`k_i+1 = k_i + 1`, `P_i+1 = P_i + G`) instead of a full scalar
+/// multiplication plus HD derivation. There is no mnemonic, so the match is reported as a raw key.
+fn run_fast_search(args: &Args, run_seed: Option<[u8; 32]>, shard: (u64, u64), targets: &[Target]) {
+    println!("Searching for vanity address using the fast incremental point-addition engine...");
+    println!("Points checked per base scalar: {}", args.points_per_base);
+
+    // Calibrate with the same per-candidate step the search loop below actually takes (one point
+    // addition + hash), not a scalar multiplication — that's the whole point of this engine, so
+    // benchmarking a scalar mult here would under-report its rate and wildly overstate the ETA.
+    let mut calibration_point = ProjectivePoint::GENERATOR * *SecretKey::random(&mut OsRng).to_nonzero_scalar();
+    let rate = calibrate_rate(|| {
+        calibration_point += ProjectivePoint::GENERATOR;
+        let encoded = calibration_point.to_affine().to_encoded_point(false);
+        std::hint::black_box(Keccak256::digest(&encoded.as_bytes()[1..]));
+    });
+    print_difficulty_table(args.mode, targets, rate);
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let start_time = Instant::now();
+    spawn_progress_thread(args, &found, &attempts, start_time, targets);
+
+    search_indices(shard).any(|i| {
+        if found.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        // Fresh base scalar for this outer iteration; the point is computed once here. Drawn from
+        // OsRng normally, or from the deterministic per-attempt ChaCha stream when --seed was given.
+        let base_sk = match run_seed {
+            Some(run_seed) => SecretKey::random(&mut seeded_rng(&run_seed, shard, i)),
+            None => SecretKey::random(&mut OsRng),
+        };
+        let mut k = *base_sk.to_nonzero_scalar();
+        let mut point = ProjectivePoint::GENERATOR * k;
+
+        for _ in 0..args.points_per_base {
+            if found.load(Ordering::Relaxed) {
+                return true;
+            }
+            attempts.fetch_add(1, Ordering::Relaxed);
+
+            let encoded = point.to_affine().to_encoded_point(false);
+            let uncompressed = encoded.as_bytes();
+            debug_assert_eq!(uncompressed[0], 0x04);
+
+            let mut hasher = Keccak256::new();
+            hasher.update(&uncompressed[1..]);
+            let digest = hasher.finalize();
+
+            let mut addr_bytes = [0u8; 20];
+            addr_bytes.copy_from_slice(&digest[12..]);
+            let addr_lower = hex::encode(addr_bytes);
+            let checksummed = to_eip55(&addr_lower);
+
+            if let Some(matched) = first_match(&addr_lower, &checksummed, args.mode, targets) {
+                // Claim the single winning slot before any printing or keystore I/O, so a second
+                // thread that also matched this instant doesn't race it into emit_private_key
+                // (which can prompt for a passphrase on stdin).
+                if found.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+                    return true;
+                }
+
+                let elapsed = start_time.elapsed();
+                let total_attempts = attempts.load(Ordering::Relaxed);
+
+                // `final_sk` itself zeroizes on drop (k256's SecretKey is zeroize-on-drop); the
+                // hex string we print from is the one copy that outlives it, so scrub that
+                // explicitly once we're done with it below.
+                let final_sk = SecretKey::from_bytes(&k.to_bytes()).expect("valid secret key");
+                let mut sk_hex = final_sk.to_bytes().encode_hex::<String>();
+
+                println!("\n🎉 Found matching address!");
+                println!("  Address (lower):   0x{}", addr_lower);
+                println!("  Address (EIP-55):  {}", checksummed);
+                emit_private_key(args.keystore.as_deref(), &addr_bytes, &final_sk, &sk_hex);
+                sk_hex.zeroize();
+
+                print_matched_target(&addr_lower, &checksummed, args.mode, matched);
+
+                println!("\n📊 Statistics:");
+                println!("  Addresses checked: {}", format_number(total_attempts));
+                println!("  Time elapsed:      {:.2} seconds", elapsed.as_secs_f64());
+                println!("  Rate:              {} addr/sec", format_number((total_attempts as f64 / elapsed.as_secs_f64()) as u64));
+
+                return true;
+            }
+
+            k += Scalar::ONE;
+            point += ProjectivePoint::GENERATOR;
+        }
+
+        false
+    });
+}
+
+/// Search for a vanity EOA address deterministically derived from a passphrase ("brain wallet").
+///
+/// The phrase itself is never used as a secret directly: each attempt appends an incrementing
+/// counter to it and iterates keccak256 `brain_rounds` times to stretch the result into a 32-byte
+/// scalar, so the exact key can always be regenerated later from `phrase + counter` alone.
+fn run_brain_search(args: &Args, phrase: &str, shard: (u64, u64), targets: &[Target]) {
+    println!("Searching for vanity address using brain-wallet passphrase stretching...");
+    println!("⚠️  A brain wallet is only as strong as its passphrase — a guessable phrase means a stealable key.");
+    println!("Stretching rounds: {}", args.brain_rounds);
+
+    let rate = calibrate_rate(|| {
+        let scalar_bytes = stretch_brain_seed(phrase, 0, args.brain_rounds);
+        if let Ok(sk) = SecretKey::from_bytes(&FieldBytes::from(scalar_bytes)) {
+            std::hint::black_box(address_from_secret_key(&sk));
+        }
+    });
+    print_difficulty_table(args.mode, targets, rate);
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let start_time = Instant::now();
+    spawn_progress_thread(args, &found, &attempts, start_time, targets);
+
+    search_indices(shard).any(|counter| {
+        if found.load(Ordering::Relaxed) {
+            return true;
+        }
+        attempts.fetch_add(1, Ordering::Relaxed);
+
+        let scalar_bytes = stretch_brain_seed(phrase, counter, args.brain_rounds);
+        let sk = match SecretKey::from_bytes(&FieldBytes::from(scalar_bytes)) {
+            Ok(sk) => sk,
+            Err(_) => return false, // stretched digest isn't a valid scalar (vanishingly rare); skip
+        };
+
+        let addr_bytes = address_from_secret_key(&sk);
+        let addr_lower = hex::encode(addr_bytes);
+        let checksummed = to_eip55(&addr_lower);
+
+        if let Some(matched) = first_match(&addr_lower, &checksummed, args.mode, targets) {
+            // Claim the single winning slot before any printing or keystore I/O, so a second
+            // thread that also matched this instant doesn't race it into emit_private_key (which
+            // can prompt for a passphrase on stdin).
+            if found.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+                return true;
+            }
+
+            let elapsed = start_time.elapsed();
+            let total_attempts = attempts.load(Ordering::Relaxed);
+            // `sk` itself zeroizes on drop (k256's SecretKey is zeroize-on-drop); the hex string
+            // we print from is the one copy that outlives it, so scrub that explicitly below.
+            let mut sk_hex = sk.to_bytes().encode_hex::<String>();
+
+            println!("\n🎉 Found matching address!");
+            println!("  Address (lower):   0x{}", addr_lower);
+            println!("  Address (EIP-55):  {}", checksummed);
+            println!("  Passphrase:        {}", phrase);
+            println!("  Counter:           {}", counter);
+            emit_private_key(args.keystore.as_deref(), &addr_bytes, &sk, &sk_hex);
+            sk_hex.zeroize();
+            println!("\n⚠️  Anyone who learns the passphrase and counter can regenerate this key — treat them like a private key.");
+
+            print_matched_target(&addr_lower, &checksummed, args.mode, matched);
+
+            println!("\n📊 Statistics:");
+            println!("  Addresses checked: {}", format_number(total_attempts));
+            println!("  Time elapsed:      {:.2} seconds", elapsed.as_secs_f64());
+            println!("  Rate:              {} addr/sec", format_number((total_attempts as f64 / elapsed.as_secs_f64()) as u64));
+
+            return true;
+        }
+
+        false
+    });
+}
+
+/// Stretch a passphrase + counter into a 32-byte scalar candidate via iterated keccak256.
+fn stretch_brain_seed(phrase: &str, counter: u64, rounds: u32) -> [u8; 32] {
+    let mut digest = Keccak256::digest(format!("{}{}", phrase, counter).as_bytes());
+    for _ in 1..rounds.max(1) {
+        digest = Keccak256::digest(&digest);
+    }
+
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest);
+    out
+}
+
+/// Search deployer nonces for a vanity CREATE contract address.
+fn run_create_search(args: &Args, deployer: [u8; 20], shard: (u64, u64), targets: &[Target]) {
+    println!("Searching for vanity CREATE contract address...");
+    println!("Deployer: 0x{}", hex::encode(deployer));
+
+    let rate = calibrate_rate(|| {
+        std::hint::black_box(create_address(&deployer, 0));
+    });
+    print_difficulty_table(args.mode, targets, rate);
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let start_time = Instant::now();
+    spawn_progress_thread(args, &found, &attempts, start_time, targets);
+
+    search_indices(shard).any(|nonce| {
+        if found.load(Ordering::Relaxed) {
+            return true;
+        }
+        attempts.fetch_add(1, Ordering::Relaxed);
+
+        let addr_bytes = create_address(&deployer, nonce);
+        let addr_lower = hex::encode(addr_bytes);
+        let checksummed = to_eip55(&addr_lower);
+
+        if let Some(matched) = first_match(&addr_lower, &checksummed, args.mode, targets) {
+            let elapsed = start_time.elapsed();
+            let total_attempts = attempts.load(Ordering::Relaxed);
+
+            println!("\n🎉 Found matching contract address!");
+            println!("  Address (lower):   0x{}", addr_lower);
+            println!("  Address (EIP-55):  {}", checksummed);
+            println!("  Deployer:          0x{}", hex::encode(deployer));
+            println!("  Nonce:             {}", nonce);
+
+            print_matched_target(&addr_lower, &checksummed, args.mode, matched);
+
+            println!("\n📊 Statistics:");
+            println!("  Nonces checked: {}", format_number(total_attempts));
+            println!("  Time elapsed:   {:.2} seconds", elapsed.as_secs_f64());
+            println!("  Rate:           {} addr/sec", format_number((total_attempts as f64 / elapsed.as_secs_f64()) as u64));
+
+            found.store(true, Ordering::SeqCst);
+            return true;
+        }
+
+        false
+    });
+}
+
+/// Search random 32-byte salts for a vanity CREATE2 contract address.
+fn run_create2_search(args: &Args, deployer: [u8; 20], init_code_hash: [u8; 32], run_seed: Option<[u8; 32]>, shard: (u64, u64), targets: &[Target]) {
+    println!("Searching for vanity CREATE2 contract address...");
+    println!("Deployer:       0x{}", hex::encode(deployer));
+    println!("Init code hash: 0x{}", hex::encode(init_code_hash));
+
+    let rate = calibrate_rate(|| {
+        let mut salt = [0u8; 32];
+        OsRng.fill_bytes(&mut salt);
+        std::hint::black_box(create2_address(&deployer, &salt, &init_code_hash));
+    });
+    print_difficulty_table(args.mode, targets, rate);
+
+    let found = Arc::new(AtomicBool::new(false));
+    let attempts = Arc::new(AtomicU64::new(0));
+    let start_time = Instant::now();
+    spawn_progress_thread(args, &found, &attempts, start_time, targets);
+
+    search_indices(shard).any(|i| {
+        if found.load(Ordering::Relaxed) {
+            return true;
+        }
+        attempts.fetch_add(1, Ordering::Relaxed);
+
+        let mut salt = [0u8; 32];
+        match run_seed {
+            Some(run_seed) => seeded_rng(&run_seed, shard, i).fill_bytes(&mut salt),
+            None => OsRng.fill_bytes(&mut salt),
+        }
+
+        let addr_bytes = create2_address(&deployer, &salt, &init_code_hash);
+        let addr_lower = hex::encode(addr_bytes);
+        let checksummed = to_eip55(&addr_lower);
+
+        if let Some(matched) = first_match(&addr_lower, &checksummed, args.mode, targets) {
+            let elapsed = start_time.elapsed();
+            let total_attempts = attempts.load(Ordering::Relaxed);
+
+            println!("\n🎉 Found matching contract address!");
+            println!("  Address (lower):   0x{}", addr_lower);
+            println!("  Address (EIP-55):  {}", checksummed);
+            println!("  Deployer:          0x{}", hex::encode(deployer));
+            println!("  Salt:              0x{}", hex::encode(salt));
+
+            print_matched_target(&addr_lower, &checksummed, args.mode, matched);
+
+            println!("\n📊 Statistics:");
+            println!("  Salts checked: {}", format_number(total_attempts));
+            println!("  Time elapsed:  {:.2} seconds", elapsed.as_secs_f64());
+            println!("  Rate:          {} addr/sec", format_number((total_attempts as f64 / elapsed.as_secs_f64()) as u64));
+
+            found.store(true, Ordering::SeqCst);
+            return true;
+        }
+
+        false
+    });
+}
+
+/// Spawn the shared progress-reporting thread used by the fast, brain, and contract search modes.
+fn spawn_progress_thread(
+    args: &Args,
+    found: &Arc<AtomicBool>,
+    attempts: &Arc<AtomicU64>,
+    start_time: Instant,
+    targets: &[Target],
+) {
+    if !args.progress {
+        return;
+    }
+
+    let found_clone = Arc::clone(found);
+    let attempts_clone = Arc::clone(attempts);
+    let interval = Duration::from_secs(args.progress_interval);
+    let progress_interval = args.progress_interval;
+    let min_expected = min_expected_attempts(args.mode, targets);
+
+    std::thread::spawn(move || {
+        let mut last_attempts = 0u64;
+        while !found_clone.load(Ordering::Relaxed) {
+            std::thread::sleep(interval);
+            let current_attempts = attempts_clone.load(Ordering::Relaxed);
+            let elapsed = start_time.elapsed().as_secs();
+            let rate = if elapsed > 0 {
+                (current_attempts - last_attempts) / progress_interval
+            } else {
+                0
+            };
+
+            let estimated_seconds = if rate > 0 {
+                min_expected / rate as f64
+            } else {
+                f64::INFINITY
+            };
+
+            eprintln!(
+                "Progress: {} addresses | Rate: {} addr/sec | Est. time: {}",
+                format_number(current_attempts),
+                format_number(rate),
+                format_duration(estimated_seconds)
+            );
+            last_attempts = current_attempts;
+        }
+    });
+}
+
+/// Print the found private key as raw hex, or, if `--keystore` was given, encrypt it into a
+/// Web3 Secret Storage (keystore-v3) JSON file under that directory instead.
+fn emit_private_key(keystore_dir: Option<&str>, addr_bytes: &[u8; 20], sk: &SecretKey, sk_hex: &str) {
+    match keystore_dir {
+        Some(dir) => match keystore::write_keystore(dir, addr_bytes, sk) {
+            Ok(path) => println!("  Keystore file:     {}", path.display()),
+            Err(e) => {
+                eprintln!("Failed to write keystore file ({}), falling back to raw output", e);
+                println!("  Private key (hex): 0x{}", sk_hex);
+            }
+        },
+        None => println!("  Private key (hex): 0x{}", sk_hex),
+    }
+}
+
+/// Build the unbounded parallel index iterator a search loop drives, restricted to one disjoint
+/// slice of the keyspace when `shard` is `(i, n)` with `n > 1`. `(0, 1)` (the default) covers the
+/// whole space exactly as before sharding existed.
+fn search_indices(shard: (u64, u64)) -> impl ParallelIterator<Item = u64> {
+    let (i, n) = shard;
+    (0u64..u64::MAX / n).into_par_iter().map(move |k| k * n + i)
+}
+
+/// Derive a deterministic ChaCha20 RNG for one search attempt, keyed by the run seed, shard, and
+/// attempt index, so a sharded search is exactly reproducible and no two shards ever repeat work.
+fn seeded_rng(seed: &[u8; 32], shard: (u64, u64), index: u64) -> ChaCha20Rng {
+    let mut hasher = Keccak256::new();
+    hasher.update(seed);
+    hasher.update(shard.0.to_le_bytes());
+    hasher.update(shard.1.to_le_bytes());
+    hasher.update(index.to_le_bytes());
+    let digest = hasher.finalize();
+
+    let mut stream_seed = [0u8; 32];
+    stream_seed.copy_from_slice(&digest);
+    ChaCha20Rng::from_seed(stream_seed)
+}
+
+/// Parse a "i/N" shard specifier (0-indexed).
+fn parse_shard(s: &str) -> Result<(u64, u64), String> {
+    let (i_str, n_str) = s.split_once('/').ok_or_else(|| "shard must be formatted \"i/N\"".to_string())?;
+    let i: u64 = i_str.parse().map_err(|_| "shard index must be a non-negative integer".to_string())?;
+    let n: u64 = n_str.parse().map_err(|_| "shard count must be a positive integer".to_string())?;
+    if n == 0 {
+        return Err("shard count N must be at least 1".to_string());
+    }
+    if i >= n {
+        return Err("shard index i must be less than N".to_string());
+    }
+    Ok((i, n))
+}
+
+/// Parse a hex-encoded 20-byte address, with or without a "0x" prefix.
+fn parse_address(s: &str) -> Result<[u8; 20], String> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let bytes = hex::decode(s).map_err(|e| e.to_string())?;
+    bytes
+        .try_into()
+        .map_err(|_| "address must be exactly 20 bytes (40 hex chars)".to_string())
+}
+
+/// Parse a hex-encoded 32-byte hash, with or without a "0x" prefix.
+fn parse_hash32(s: &str) -> Result<[u8; 32], String> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let bytes = hex::decode(s).map_err(|e| e.to_string())?;
+    bytes
+        .try_into()
+        .map_err(|_| "hash must be exactly 32 bytes (64 hex chars)".to_string())
+}
+
+/// RLP-encode a nonce as a minimal big-endian integer (0 encodes as the empty-string byte 0x80).
+fn rlp_encode_nonce(nonce: u64) -> Vec<u8> {
+    if nonce == 0 {
+        return vec![0x80];
+    }
+    let be = nonce.to_be_bytes();
+    let first_nonzero = be.iter().position(|&b| b != 0).unwrap();
+    let trimmed = &be[first_nonzero..];
+
+    if trimmed.len() == 1 && trimmed[0] < 0x80 {
+        vec![trimmed[0]]
+    } else {
+        let mut out = vec![0x80 + trimmed.len() as u8];
+        out.extend_from_slice(trimmed);
+        out
+    }
+}
+
+/// Compute the CREATE contract address: `keccak256(rlp([deployer, nonce]))[12..]`.
+fn create_address(deployer: &[u8; 20], nonce: u64) -> [u8; 20] {
+    let mut addr_item = vec![0x94];
+    addr_item.extend_from_slice(deployer);
+    let nonce_item = rlp_encode_nonce(nonce);
+
+    let payload_len = addr_item.len() + nonce_item.len();
+    let mut rlp = vec![0xc0 + payload_len as u8];
+    rlp.extend_from_slice(&addr_item);
+    rlp.extend_from_slice(&nonce_item);
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&rlp);
+    let digest = hasher.finalize();
+
+    let mut addr = [0u8; 20];
+    addr.copy_from_slice(&digest[12..]);
+    addr
+}
+
+/// Compute the CREATE2 contract address: `keccak256(0xff ++ deployer ++ salt ++ init_code_hash)[12..]`.
+fn create2_address(deployer: &[u8; 20], salt: &[u8; 32], init_code_hash: &[u8; 32]) -> [u8; 20] {
+    let mut preimage = Vec::with_capacity(1 + 20 + 32 + 32);
+    preimage.push(0xff);
+    preimage.extend_from_slice(deployer);
+    preimage.extend_from_slice(salt);
+    preimage.extend_from_slice(init_code_hash);
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&preimage);
+    let digest = hasher.finalize();
+
+    let mut addr = [0u8; 20];
+    addr.copy_from_slice(&digest[12..]);
+    addr
+}
+
 /// Generate a key from BIP-39 seed with HD derivation
 fn gen_key_from_seed(seed: &[u8; 64], base_path: &DerivationPath, index: u32) -> (SecretKey, [u8; 20], Option<u32>) {
     use hmac::Mac;
@@ -316,9 +1145,14 @@ fn gen_key_from_seed(seed: &[u8; 64], base_path: &DerivationPath, index: u32) ->
     // The XPriv contains a k256::ecdsa::SigningKey which we can access via AsRef
     let signing_key: &k256::ecdsa::SigningKey = derived.as_ref();
     let sk = SecretKey::from_slice(&signing_key.to_bytes()).expect("valid secret key");
-    let pk = PublicKey::from_secret_scalar(&sk.to_nonzero_scalar());
+    let addr = address_from_secret_key(&sk);
 
-    // Generate address (same as gen_key_and_address)
+    (sk, addr, Some(index))
+}
+
+/// Derive the Ethereum address for a secret key: `keccak256(uncompressed_pubkey[1..])[12..]`.
+fn address_from_secret_key(sk: &SecretKey) -> [u8; 20] {
+    let pk = PublicKey::from_secret_scalar(&sk.to_nonzero_scalar());
     let enc = pk.to_encoded_point(false);
     let uncompressed = enc.as_bytes();
     debug_assert_eq!(uncompressed[0], 0x04);
@@ -329,8 +1163,7 @@ fn gen_key_from_seed(seed: &[u8; 64], base_path: &DerivationPath, index: u32) ->
 
     let mut addr = [0u8; 20];
     addr.copy_from_slice(&digest[12..]);
-
-    (sk, addr, Some(index))
+    addr
 }
 
 /// EIP-55 checksum: given a lowercase hex address without 0x, produce "0x..." with mixed case.